@@ -1,10 +1,41 @@
 use std::fmt::{self, Write};
 use super::list;
 
-#[derive(Debug, PartialEq)]
+/// A (start, end) character offset range into the source that was parsed.
+///
+pub type Span = (usize, usize);
+
+/// Wraps a value together with the span of source it was parsed from.
+///
+/// Equality only considers the wrapped value, not the span, so
+/// that ASTs built for comparison in tests don't need to track real spans.
+///
+#[derive(Debug, Clone)]
+pub struct Spanned<T> {
+    pub node: T,
+    pub span: Span,
+}
+
+impl<T> Spanned<T> {
+    pub fn new(node: T, span: Span) -> Self {
+        Spanned {
+            node: node,
+            span: span,
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Spanned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Node {
-    List(list::List<Node>),
-    Float(i32),
+    List(list::List<Spanned<Node>>),
+    Int(i64),
+    Float(f64),
     Atom(String),
     String(String),
     True,
@@ -33,9 +64,16 @@ impl Node {
     }
 
 
+    /// Constuct a new Int node
+    ///
+    pub fn int(i: i64) -> Self {
+        Node::Int(i)
+    }
+
+
     /// Constuct a new Float node
     ///
-    pub fn float(f: i32) -> Self {
+    pub fn float(f: f64) -> Self {
         Node::Float(f)
     }
 
@@ -47,17 +85,20 @@ impl Node {
     }
 
 
-    /// Constuct a new List node
+    /// Constuct a new List node from a list of spanned nodes
     ///
-    pub fn list(l: list::List<Node>) -> Self {
+    pub fn list(l: list::List<Spanned<Node>>) -> Self {
         Node::List(l)
     }
 
 
-    /// Constuct a new List node from a vector of nodes
+    /// Constuct a new List node from a vector of nodes, giving each
+    /// element a dummy span. Handy for building expected trees in tests;
+    /// the parser itself threads real spans through `Node::list`.
     ///
     pub fn list_from_vec(l: Vec<Node>) -> Self {
-        Node::List(list::List::from_vec(l))
+        let spanned = l.into_iter().map(|n| Spanned::new(n, (0, 0))).collect();
+        Node::List(list::List::from_vec(spanned))
     }
 
 
@@ -67,6 +108,8 @@ impl Node {
         match *self {
             Node::True => write!(buffer, "true"),
             Node::False => write!(buffer, "false"),
+            Node::Int(i) => write!(buffer, "{}", i),
+            Node::Float(f) if f.fract() == 0.0 && f.is_finite() => write!(buffer, "{:.1}", f),
             Node::Float(f) => write!(buffer, "{}", f),
             Node::String(ref s) => write!(buffer, "{:?}", s),
             Node::Atom(ref a) => write!(buffer, "{}", a),
@@ -76,11 +119,11 @@ impl Node {
 }
 
 
-fn print_list(buffer: &mut String, list: &list::List<Node>) -> Result<(), fmt::Error> {
+fn print_list(buffer: &mut String, list: &list::List<Spanned<Node>>) -> Result<(), fmt::Error> {
     try!(write!(buffer, "("));
     let mut iter = list.iter().peekable();
     while let Some(x) = iter.next() {
-        x.print(buffer).unwrap();
+        x.node.print(buffer).unwrap();
         if iter.peek().is_some() {
             try!(write!(buffer, " "))
         }
@@ -116,12 +159,26 @@ mod test {
     }
 
     #[test]
-    fn print_float() {
+    fn print_int() {
         let mut buffer = String::new();
-        Node::float(5).print(&mut buffer).unwrap();
+        Node::int(5).print(&mut buffer).unwrap();
         assert_eq!(buffer, "5".to_string())
     }
 
+    #[test]
+    fn print_float() {
+        let mut buffer = String::new();
+        Node::float(5.5).print(&mut buffer).unwrap();
+        assert_eq!(buffer, "5.5".to_string())
+    }
+
+    #[test]
+    fn print_float_whole_number() {
+        let mut buffer = String::new();
+        Node::float(5.0).print(&mut buffer).unwrap();
+        assert_eq!(buffer, "5.0".to_string())
+    }
+
     #[test]
     fn print_atom() {
         let mut buffer = String::new();
@@ -137,10 +194,14 @@ mod test {
         assert_eq!(buffer, "()".to_string())
     }
 
+    fn spanned(n: Node) -> Spanned<Node> {
+        Spanned::new(n, (0, 0))
+    }
+
     #[test]
     fn print_list_1() {
         let mut buffer = String::new();
-        let list = List::new().cons(Node::float(5));
+        let list = List::new().cons(spanned(Node::int(5)));
         Node::list(list).print(&mut buffer).unwrap();
         assert_eq!(buffer, "(5)".to_string())
     }
@@ -148,7 +209,9 @@ mod test {
     #[test]
     fn print_list_2() {
         let mut buffer = String::new();
-        let list = List::new().cons(Node::float(5)).cons(Node::atom("-".to_string()));
+        let list = List::new()
+            .cons(spanned(Node::int(5)))
+            .cons(spanned(Node::atom("-".to_string())));
         Node::list(list).print(&mut buffer).unwrap();
         assert_eq!(buffer, "(- 5)".to_string())
     }
@@ -157,9 +220,9 @@ mod test {
     fn print_list_3() {
         let mut buffer = String::new();
         let list = List::new()
-            .cons(Node::float(40))
-            .cons(Node::float(5))
-            .cons(Node::atom("-".to_string()));
+            .cons(spanned(Node::int(40)))
+            .cons(spanned(Node::int(5)))
+            .cons(spanned(Node::atom("-".to_string())));
         Node::list(list).print(&mut buffer).unwrap();
         assert_eq!(buffer, "(- 5 40)".to_string())
     }
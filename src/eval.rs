@@ -0,0 +1,388 @@
+use std::rc::Rc;
+use super::ast::{Node, Spanned};
+use super::list::List;
+
+/// A runtime value produced by evaluating a `Node`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    String(String),
+    Bool(bool),
+    Atom(String),
+    List(List<Value>),
+    Lambda(Rc<Lambda>),
+    Builtin(Builtin),
+}
+
+/// A closure: the parameter names, the body to evaluate, and the `Env`
+/// it was defined in, captured so that it is still available however
+/// long the lambda outlives the scope that created it.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lambda {
+    pub params: Vec<String>,
+    pub body: Node,
+    pub env: Env,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Builtin {
+    Add,
+    Sub,
+    Mul,
+    Eq,
+    Cons,
+    Head,
+    Tail,
+}
+
+/// A persistent environment of variable bindings. Entering a new scope
+/// is an O(1) `cons`; leaving it just drops the extended list, leaving
+/// the outer bindings, and anything still holding a reference to them,
+/// untouched.
+///
+pub type Env = List<(String, Value)>;
+
+#[derive(Debug, PartialEq)]
+pub enum EvalError {
+    UnboundAtom(String),
+    NotCallable,
+    ArityMismatch,
+}
+
+/// The environment a top-level form is usually evaluated in: the
+/// builtins bound to their names.
+///
+pub fn global_env() -> Env {
+    Env::new()
+        .cons(("+".to_string(), Value::Builtin(Builtin::Add)))
+        .cons(("-".to_string(), Value::Builtin(Builtin::Sub)))
+        .cons(("*".to_string(), Value::Builtin(Builtin::Mul)))
+        .cons(("=".to_string(), Value::Builtin(Builtin::Eq)))
+        .cons(("cons".to_string(), Value::Builtin(Builtin::Cons)))
+        .cons(("head".to_string(), Value::Builtin(Builtin::Head)))
+        .cons(("tail".to_string(), Value::Builtin(Builtin::Tail)))
+}
+
+/// Evaluate a parsed `Node` into a runtime `Value`.
+///
+pub fn eval(node: &Node, env: &Env) -> Result<Value, EvalError> {
+    match *node {
+        Node::Int(i) => Ok(Value::Int(i)),
+        Node::Float(f) => Ok(Value::Float(f)),
+        Node::String(ref s) => Ok(Value::String(s.clone())),
+        Node::True => Ok(Value::Bool(true)),
+        Node::False => Ok(Value::Bool(false)),
+        Node::Atom(ref name) => lookup(env, name),
+        Node::List(ref list) => eval_list(list, env),
+    }
+}
+
+fn lookup(env: &Env, name: &str) -> Result<Value, EvalError> {
+    for &(ref bound_name, ref value) in env.iter() {
+        if bound_name == name {
+            return Ok(value.clone());
+        }
+    }
+    Err(EvalError::UnboundAtom(name.to_string()))
+}
+
+fn eval_list(elements: &List<Spanned<Node>>, env: &Env) -> Result<Value, EvalError> {
+    let head = match elements.head() {
+        Some(head) => head,
+        None => return Ok(Value::List(List::new())),
+    };
+    let rest = elements.tail();
+
+    if let Node::Atom(ref name) = head.node {
+        match name.as_str() {
+            "quote" => return eval_quote(&rest),
+            "if" => return eval_if(&rest, env),
+            "let" => return eval_let(&rest, env),
+            "lambda" => return eval_lambda(&rest, env),
+            _ => {}
+        }
+    }
+
+    let callable = eval(&head.node, env)?;
+    let mut args = Vec::new();
+    for spanned in rest.iter() {
+        args.push(eval(&spanned.node, env)?);
+    }
+    apply(callable, args)
+}
+
+fn quote_to_value(node: &Node) -> Value {
+    match *node {
+        Node::Int(i) => Value::Int(i),
+        Node::Float(f) => Value::Float(f),
+        Node::String(ref s) => Value::String(s.clone()),
+        Node::True => Value::Bool(true),
+        Node::False => Value::Bool(false),
+        Node::Atom(ref a) => Value::Atom(a.clone()),
+        Node::List(ref list) => {
+            let values = list.iter().map(|spanned| quote_to_value(&spanned.node)).collect();
+            Value::List(List::from_vec(values))
+        }
+    }
+}
+
+fn eval_quote(rest: &List<Spanned<Node>>) -> Result<Value, EvalError> {
+    match (rest.head(), rest.tail().head()) {
+        (Some(form), None) => Ok(quote_to_value(&form.node)),
+        _ => Err(EvalError::ArityMismatch),
+    }
+}
+
+fn eval_if(rest: &List<Spanned<Node>>, env: &Env) -> Result<Value, EvalError> {
+    let cond = rest.head().ok_or(EvalError::ArityMismatch)?;
+    let rest = rest.tail();
+    let then_branch = rest.head().ok_or(EvalError::ArityMismatch)?;
+    let rest = rest.tail();
+    let else_branch = rest.head().ok_or(EvalError::ArityMismatch)?;
+    if rest.tail().head().is_some() {
+        return Err(EvalError::ArityMismatch);
+    }
+
+    if is_truthy(&eval(&cond.node, env)?) {
+        eval(&then_branch.node, env)
+    } else {
+        eval(&else_branch.node, env)
+    }
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match *value {
+        Value::Bool(b) => b,
+        _ => true,
+    }
+}
+
+fn eval_let(rest: &List<Spanned<Node>>, env: &Env) -> Result<Value, EvalError> {
+    let name_form = rest.head().ok_or(EvalError::ArityMismatch)?;
+    let rest = rest.tail();
+    let value_form = rest.head().ok_or(EvalError::ArityMismatch)?;
+    let rest = rest.tail();
+    let body_form = rest.head().ok_or(EvalError::ArityMismatch)?;
+    if rest.tail().head().is_some() {
+        return Err(EvalError::ArityMismatch);
+    }
+
+    let name = match name_form.node {
+        Node::Atom(ref name) => name.clone(),
+        _ => return Err(EvalError::ArityMismatch),
+    };
+    let value = eval(&value_form.node, env)?;
+    let scope = env.cons((name, value));
+    eval(&body_form.node, &scope)
+}
+
+fn eval_lambda(rest: &List<Spanned<Node>>, env: &Env) -> Result<Value, EvalError> {
+    let params_form = rest.head().ok_or(EvalError::ArityMismatch)?;
+    let rest = rest.tail();
+    let body_form = rest.head().ok_or(EvalError::ArityMismatch)?;
+    if rest.tail().head().is_some() {
+        return Err(EvalError::ArityMismatch);
+    }
+
+    let params = match params_form.node {
+        Node::List(ref params) => {
+            let mut names = Vec::new();
+            for param in params.iter() {
+                match param.node {
+                    Node::Atom(ref name) => names.push(name.clone()),
+                    _ => return Err(EvalError::ArityMismatch),
+                }
+            }
+            names
+        }
+        _ => return Err(EvalError::ArityMismatch),
+    };
+
+    let lambda = Lambda {
+        params: params,
+        body: body_form.node.clone(),
+        env: env.clone(),
+    };
+    Ok(Value::Lambda(Rc::new(lambda)))
+}
+
+fn apply(callable: Value, args: Vec<Value>) -> Result<Value, EvalError> {
+    match callable {
+        Value::Builtin(builtin) => apply_builtin(builtin, args),
+        Value::Lambda(lambda) => apply_lambda(&lambda, args),
+        _ => Err(EvalError::NotCallable),
+    }
+}
+
+fn apply_lambda(lambda: &Lambda, args: Vec<Value>) -> Result<Value, EvalError> {
+    if args.len() != lambda.params.len() {
+        return Err(EvalError::ArityMismatch);
+    }
+    let mut scope = lambda.env.clone();
+    for (name, value) in lambda.params.iter().cloned().zip(args) {
+        scope = scope.cons((name, value));
+    }
+    eval(&lambda.body, &scope)
+}
+
+enum Num {
+    Int(i64),
+    Float(f64),
+}
+
+impl Num {
+    fn as_f64(&self) -> f64 {
+        match *self {
+            Num::Int(i) => i as f64,
+            Num::Float(f) => f,
+        }
+    }
+}
+
+fn to_num(value: Value) -> Result<Num, EvalError> {
+    match value {
+        Value::Int(i) => Ok(Num::Int(i)),
+        Value::Float(f) => Ok(Num::Float(f)),
+        _ => Err(EvalError::ArityMismatch),
+    }
+}
+
+fn binary_args(mut args: Vec<Value>) -> Result<(Value, Value), EvalError> {
+    if args.len() != 2 {
+        return Err(EvalError::ArityMismatch);
+    }
+    let b = args.pop().unwrap();
+    let a = args.pop().unwrap();
+    Ok((a, b))
+}
+
+fn binary_arith<FI, FF>(args: Vec<Value>, int_op: FI, float_op: FF) -> Result<Value, EvalError>
+    where FI: Fn(i64, i64) -> i64,
+          FF: Fn(f64, f64) -> f64
+{
+    let (a, b) = binary_args(args)?;
+    match (to_num(a)?, to_num(b)?) {
+        (Num::Int(x), Num::Int(y)) => Ok(Value::Int(int_op(x, y))),
+        (x, y) => Ok(Value::Float(float_op(x.as_f64(), y.as_f64()))),
+    }
+}
+
+fn apply_builtin(builtin: Builtin, args: Vec<Value>) -> Result<Value, EvalError> {
+    match builtin {
+        Builtin::Add => binary_arith(args, |a, b| a + b, |a, b| a + b),
+        Builtin::Sub => binary_arith(args, |a, b| a - b, |a, b| a - b),
+        Builtin::Mul => binary_arith(args, |a, b| a * b, |a, b| a * b),
+        Builtin::Eq => {
+            let (a, b) = binary_args(args)?;
+            Ok(Value::Bool(a == b))
+        }
+        Builtin::Cons => {
+            let (head, tail) = binary_args(args)?;
+            match tail {
+                Value::List(list) => Ok(Value::List(list.cons(head))),
+                _ => Err(EvalError::ArityMismatch),
+            }
+        }
+        Builtin::Head => {
+            let mut args = args;
+            if args.len() != 1 {
+                return Err(EvalError::ArityMismatch);
+            }
+            match args.pop().unwrap() {
+                Value::List(list) => list.head().cloned().ok_or(EvalError::ArityMismatch),
+                _ => Err(EvalError::ArityMismatch),
+            }
+        }
+        Builtin::Tail => {
+            let mut args = args;
+            if args.len() != 1 {
+                return Err(EvalError::ArityMismatch);
+            }
+            match args.pop().unwrap() {
+                Value::List(list) => Ok(Value::List(list.tail())),
+                _ => Err(EvalError::ArityMismatch),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::parser;
+
+    fn eval_str(src: &str) -> Value {
+        let nodes = parser::parse(src).unwrap();
+        eval(&nodes[0].node, &global_env()).unwrap()
+    }
+
+    #[test]
+    fn eval_self_evaluating() {
+        assert_eq!(eval_str("5"), Value::Int(5));
+        assert_eq!(eval_str("5.5"), Value::Float(5.5));
+        assert_eq!(eval_str("\"hi\""), Value::String("hi".to_string()));
+        assert_eq!(eval_str("true"), Value::Bool(true));
+        assert_eq!(eval_str("false"), Value::Bool(false));
+    }
+
+    #[test]
+    fn eval_unbound_atom() {
+        let nodes = parser::parse("nope").unwrap();
+        let result = eval(&nodes[0].node, &global_env());
+        assert_eq!(result, Err(EvalError::UnboundAtom("nope".to_string())));
+    }
+
+    #[test]
+    fn eval_builtin_arithmetic() {
+        assert_eq!(eval_str("(+ 1 2)"), Value::Int(3));
+        assert_eq!(eval_str("(- 5 2)"), Value::Int(3));
+        assert_eq!(eval_str("(* 2 3)"), Value::Int(6));
+        assert_eq!(eval_str("(+ 1 2.5)"), Value::Float(3.5));
+        assert_eq!(eval_str("(= 1 1)"), Value::Bool(true));
+        assert_eq!(eval_str("(= 1 2)"), Value::Bool(false));
+    }
+
+    #[test]
+    fn eval_list_builtins() {
+        assert_eq!(eval_str("(cons 1 (quote (2 3)))"),
+                   Value::List(List::from_vec(vec![Value::Int(1), Value::Int(2), Value::Int(3)])));
+        assert_eq!(eval_str("(head (quote (1 2 3)))"), Value::Int(1));
+        assert_eq!(eval_str("(tail (quote (1 2 3)))"),
+                   Value::List(List::from_vec(vec![Value::Int(2), Value::Int(3)])));
+    }
+
+    #[test]
+    fn eval_quote_does_not_evaluate() {
+        assert_eq!(eval_str("(quote x)"), Value::Atom("x".to_string()));
+        assert_eq!(eval_str("(quote (+ 1 2))"),
+                   Value::List(List::from_vec(vec![Value::Atom("+".to_string()),
+                                                    Value::Int(1),
+                                                    Value::Int(2)])));
+    }
+
+    #[test]
+    fn eval_if_branches() {
+        assert_eq!(eval_str("(if true 1 2)"), Value::Int(1));
+        assert_eq!(eval_str("(if false 1 2)"), Value::Int(2));
+    }
+
+    #[test]
+    fn eval_let_binds_a_name() {
+        assert_eq!(eval_str("(let x 1 (+ x 1))"), Value::Int(2));
+    }
+
+    #[test]
+    fn eval_lambda_application() {
+        assert_eq!(eval_str("((lambda (x) (+ x 1)) 4)"), Value::Int(5));
+    }
+
+    #[test]
+    fn eval_closure_outlives_defining_scope() {
+        let adder = eval_str("(let x 10 (lambda (y) (+ x y)))");
+        let result = apply(adder, vec![Value::Int(5)]).unwrap();
+        assert_eq!(result, Value::Int(15));
+    }
+}
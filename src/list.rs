@@ -1,5 +1,6 @@
 use std::rc::Rc;
 use std::fmt;
+use std::iter::FromIterator;
 
 //
 // Adapted from Alexis Beingessner's "Learning Rust With
@@ -15,6 +16,15 @@ pub struct List<T> {
     head: Link<T>,
 }
 
+// Cloning a list is just a reference count bump on the head node, so it
+// doesn't need `T: Clone` the way `#[derive(Clone)]` would require.
+//
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        List { head: self.head.clone() }
+    }
+}
+
 #[derive(PartialEq)]
 struct Node<T> {
     elem: T,
@@ -85,6 +95,43 @@ impl<T> List<T> {
     pub fn is_empty(&self) -> bool {
         self.head().is_none()
     }
+
+
+    /// Count the elements in the list.
+    ///
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+
+    /// Fetch the element at the given index, if there is one.
+    ///
+    pub fn nth(&self, i: usize) -> Option<&T> {
+        self.iter().nth(i)
+    }
+}
+
+impl<T: Clone> List<T> {
+    /// Construct a new list with the elements in the opposite order.
+    ///
+    pub fn reverse(&self) -> Self {
+        let mut elements: Vec<T> = self.iter().cloned().collect();
+        elements.reverse();
+        List::from_vec(elements)
+    }
+
+
+    /// Construct a new list containing the elements of this list
+    /// followed by the elements of `other`, structurally sharing
+    /// `other` rather than copying it.
+    ///
+    pub fn append(&self, other: &Self) -> Self {
+        let mut result = other.clone();
+        for elem in self.iter().cloned().collect::<Vec<_>>().into_iter().rev() {
+            result = result.cons(elem);
+        }
+        result
+    }
 }
 
 impl<T: fmt::Debug> fmt::Debug for List<T> {
@@ -110,9 +157,32 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        List::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = ::std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter().cloned().collect::<Vec<T>>().into_iter()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::List;
+    use std::rc::Rc;
 
     #[test]
     fn cons_head_and_tail() {
@@ -191,4 +261,88 @@ mod test {
         assert_eq!(List::from_vec(vec![1, 2, 3]),
                    List::new().cons(3).cons(2).cons(1));
     }
+
+    #[test]
+    fn len() {
+        let list: List<u8> = List::new();
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.cons(1).cons(2).cons(3).len(), 3);
+    }
+
+    #[test]
+    fn nth() {
+        let list = List::new().cons(3).cons(2).cons(1);
+        assert_eq!(list.nth(0), Some(&1));
+        assert_eq!(list.nth(1), Some(&2));
+        assert_eq!(list.nth(2), Some(&3));
+        assert_eq!(list.nth(3), None);
+    }
+
+    /// Collect the raw node pointers making up a list, innermost last, so
+    /// that structural sharing between two lists can be checked by
+    /// pointer identity rather than value equality.
+    ///
+    fn node_ptrs<T>(list: &List<T>) -> Vec<*const ()> {
+        let mut ptrs = Vec::new();
+        let mut link = list.head.clone();
+        while let Some(node) = link {
+            ptrs.push(Rc::as_ptr(&node) as *const ());
+            link = node.next.clone();
+        }
+        ptrs
+    }
+
+    #[test]
+    fn reverse() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.reverse(), List::from_vec(vec![3, 2, 1]));
+        assert_eq!(list, List::from_vec(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn reverse_shares_no_structure_with_the_original() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let reversed = list.reverse();
+        let original_ptrs = node_ptrs(&list);
+        let reversed_ptrs = node_ptrs(&reversed);
+        assert!(original_ptrs.iter().all(|ptr| !reversed_ptrs.contains(ptr)));
+    }
+
+    #[test]
+    fn append() {
+        let list1 = List::from_vec(vec![1, 2]);
+        let list2 = List::from_vec(vec![3, 4]);
+        assert_eq!(list1.append(&list2), List::from_vec(vec![1, 2, 3, 4]));
+        assert_eq!(list1, List::from_vec(vec![1, 2]));
+        assert_eq!(list2, List::from_vec(vec![3, 4]));
+    }
+
+    #[test]
+    fn append_shares_the_other_lists_structure() {
+        let list1 = List::new().cons(1);
+        let list2 = List::new().cons(3).cons(2);
+        let appended = list1.append(&list2);
+        assert_eq!(appended.tail(), list2);
+        assert_eq!(node_ptrs(&appended.tail()), node_ptrs(&list2));
+    }
+
+    #[test]
+    fn from_iter() {
+        let list: List<i32> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(list, List::from_vec(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn into_iter_by_reference() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let collected: Vec<&i32> = (&list).into_iter().collect();
+        assert_eq!(collected, vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn into_iter_by_value() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let collected: Vec<i32> = list.into_iter().collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
 }
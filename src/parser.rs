@@ -1,27 +1,86 @@
 use std::iter::Peekable;
 use std::str;
-use super::ast::Node;
+use super::ast::{Node, Spanned};
+use super::list;
 
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
-    ReservedChar,
-    BadList,
+    ReservedChar(usize, usize),
+    BadList(usize, usize),
+    BadString(usize, usize),
+    BadEscape(usize, usize),
 }
 
 enum ParseResult {
-    Ok(Node),
+    Ok(Spanned<Node>),
     None,
     Err(ParseError),
 }
 
+/// A source cursor that tracks the character offset, line and column of
+/// the next character to be read, so that spans and parse errors can
+/// report where in the source they came from.
+///
+#[derive(Clone)]
+struct Cursor<'a> {
+    chars: Peekable<str::Chars<'a>>,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Cursor {
+            chars: input.chars().peekable(),
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&mut self) -> Option<&char> {
+        self.chars.peek()
+    }
+
+    /// Peek at the character after the one `peek` would return, without
+    /// advancing the cursor.
+    ///
+    fn peek_second(&self) -> Option<char> {
+        let mut chars = self.chars.clone();
+        chars.next();
+        chars.next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if let Some(ch) = c {
+            self.offset += 1;
+            if ch == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        c
+    }
+
+    /// The (line, column) of the next character to be read.
+    ///
+    fn position(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+}
+
 /// Parse source code into an abstract syntax tree.
 ///
-pub fn parse(input: &str) -> Result<Vec<Node>, ParseError> {
-    let mut chars = input.chars().peekable();
-    parse_nodes(&mut chars)
+pub fn parse(input: &str) -> Result<Vec<Spanned<Node>>, ParseError> {
+    let mut cursor = Cursor::new(input);
+    parse_nodes(&mut cursor)
 }
 
-fn parse_nodes(mut chars: &mut Peekable<str::Chars>) -> Result<Vec<Node>, ParseError> {
+fn parse_nodes(mut chars: &mut Cursor) -> Result<Vec<Spanned<Node>>, ParseError> {
     let mut nodes = vec![];
     loop {
         chomp(&mut chars);
@@ -34,20 +93,55 @@ fn parse_nodes(mut chars: &mut Peekable<str::Chars>) -> Result<Vec<Node>, ParseE
     Ok(nodes)
 }
 
-fn parse_node(mut chars: &mut Peekable<str::Chars>) -> ParseResult {
+fn parse_node(mut chars: &mut Cursor) -> ParseResult {
+    let start = chars.offset;
+    if let Some(result) = parse_quote_like(&mut chars, start) {
+        return result;
+    }
     if starts_with_reserved_char(&mut chars) {
-        return ParseResult::Err(ParseError::ReservedChar);
+        let (line, column) = chars.position();
+        return ParseResult::Err(ParseError::ReservedChar(line, column));
     }
-    if let Some(atom) = parse_atom(&mut chars) {
-        return ParseResult::Ok(atom);
+    if let Some(result) = parse_string(&mut chars, start) {
+        return result;
     }
     if let Some(num) = parse_number(&mut chars) {
-        return ParseResult::Ok(num);
+        return ParseResult::Ok(Spanned::new(num, (start, chars.offset)));
+    }
+    if let Some(atom) = parse_atom(&mut chars) {
+        return ParseResult::Ok(Spanned::new(atom, (start, chars.offset)));
     }
-    parse_list(&mut chars)
+    parse_list(&mut chars, start)
 }
 
-fn parse_atom(chars: &mut Peekable<str::Chars>) -> Option<Node> {
+/// Parse a quote reader macro: `'x`, `` `x `` and `,x` expand to
+/// `(quote x)`, `(quasiquote x)` and `(unquote x)` respectively, each
+/// wrapping the single node that follows the prefix character.
+///
+/// Returns `None` if the input does not start with one of these prefixes.
+///
+fn parse_quote_like(chars: &mut Cursor, start: usize) -> Option<ParseResult> {
+    let keyword = match chars.peek() {
+        Some(&'\'') => "quote",
+        Some(&'`') => "quasiquote",
+        Some(&',') => "unquote",
+        _ => return None,
+    };
+    chars.next();
+    let symbol = Spanned::new(Node::atom(keyword.to_string()), (start, chars.offset));
+    let inner = match parse_node(chars) {
+        ParseResult::Ok(node) => node,
+        ParseResult::Err(error) => return Some(ParseResult::Err(error)),
+        ParseResult::None => {
+            let (line, column) = chars.position();
+            return Some(ParseResult::Err(ParseError::BadList(line, column)));
+        }
+    };
+    let node = Node::list(list::List::from_vec(vec![symbol, inner]));
+    Some(ParseResult::Ok(Spanned::new(node, (start, chars.offset))))
+}
+
+fn parse_atom(chars: &mut Cursor) -> Option<Node> {
     let mut buffer = String::new();
     if !valid_atom_start_char(chars) {
         return None;
@@ -67,15 +161,55 @@ fn parse_atom(chars: &mut Peekable<str::Chars>) -> Option<Node> {
     }
 }
 
-fn starts_with_reserved_char(chars: &mut Peekable<str::Chars>) -> bool {
+fn starts_with_reserved_char(chars: &mut Cursor) -> bool {
     match chars.peek() {
-        Some(&'#') | Some(&'[') | Some(&']') | Some(&'{') | Some(&'}') | Some(&'"') |
-        Some(&'\'') | Some(&'`') => true,
+        Some(&'#') | Some(&'[') | Some(&']') | Some(&'{') | Some(&'}') => true,
         _ => false,
     }
 }
 
-fn valid_atom_start_char(chars: &mut Peekable<str::Chars>) -> bool {
+/// Parse a string literal, e.g. `"hello\nworld"`.
+///
+/// Returns `None` if the input does not start with `"`, so callers can
+/// fall through to try other node kinds.
+///
+fn parse_string(chars: &mut Cursor, start: usize) -> Option<ParseResult> {
+    if chars.peek() != Some(&'"') {
+        return None;
+    }
+    let string_pos = chars.position();
+    chars.next();
+    let mut buffer = String::new();
+    loop {
+        let escape_pos = chars.position();
+        match chars.next() {
+            None => {
+                let (line, column) = string_pos;
+                return Some(ParseResult::Err(ParseError::BadString(line, column)));
+            }
+            Some('"') => {
+                let node = Node::string(buffer);
+                return Some(ParseResult::Ok(Spanned::new(node, (start, chars.offset))));
+            }
+            Some('\\') => {
+                match chars.next() {
+                    Some('"') => buffer.push('"'),
+                    Some('\\') => buffer.push('\\'),
+                    Some('n') => buffer.push('\n'),
+                    Some('t') => buffer.push('\t'),
+                    Some('r') => buffer.push('\r'),
+                    _ => {
+                        let (line, column) = escape_pos;
+                        return Some(ParseResult::Err(ParseError::BadEscape(line, column)));
+                    }
+                }
+            }
+            Some(c) => buffer.push(c),
+        }
+    }
+}
+
+fn valid_atom_start_char(chars: &mut Cursor) -> bool {
     !starts_with_reserved_char(chars) &&
     match chars.peek() {
         Some(&'(') | Some(&')') | None => false,
@@ -83,31 +217,89 @@ fn valid_atom_start_char(chars: &mut Peekable<str::Chars>) -> bool {
     }
 }
 
-fn parse_number(chars: &mut Peekable<str::Chars>) -> Option<Node> {
-    let mut point = false;
-    let mut nums = String::new();
-    while let Some(&c) = chars.peek() {
-        if !point && c == '.' {
-            point = true;
-            nums.push(c);
-            chars.next();
-        } else if c.is_digit(10) {
-            nums.push(c);
-            chars.next();
+/// Parse a number, recognising an optional leading `-`, an integer part,
+/// an optional fractional part, and an optional exponent (`e`/`E`).
+///
+/// A token with neither a `.` nor an exponent becomes an `Int`, otherwise
+/// a `Float`. A bare `-` with no following digit is left untouched so
+/// that it can be parsed as the subtraction atom.
+///
+fn parse_number(chars: &mut Cursor) -> Option<Node> {
+    let mut fractional = false;
+    let mut exponent = false;
+    let mut buffer = String::new();
+    let mut lookahead = chars.clone();
+
+    if lookahead.peek() == Some(&'-') {
+        buffer.push('-');
+        lookahead.next();
+    }
+    if lookahead.peek().map_or(false, |c| c.is_digit(10)) {
+        // Integer part.
+    } else if lookahead.peek() == Some(&'.') &&
+              lookahead.peek_second().map_or(false, |c| c.is_digit(10)) {
+        // Leading-dot float, e.g. `.5`.
+    } else {
+        return None;
+    }
+
+    while let Some(&c) = lookahead.peek() {
+        if c.is_digit(10) {
+            buffer.push(c);
+            lookahead.next();
+        } else if !fractional && !exponent && c == '.' {
+            fractional = true;
+            buffer.push(c);
+            lookahead.next();
+        } else if !exponent && (c == 'e' || c == 'E') {
+            let mut after_e = lookahead.clone();
+            after_e.next();
+            let has_sign = after_e.peek() == Some(&'+') || after_e.peek() == Some(&'-');
+            if has_sign {
+                after_e.next();
+            }
+            if after_e.peek().map_or(false, |c| c.is_digit(10)) {
+                exponent = true;
+                buffer.push(c);
+                lookahead.next();
+                if has_sign {
+                    buffer.push(*lookahead.peek().unwrap());
+                    lookahead.next();
+                }
+            } else {
+                break;
+            }
         } else {
             break;
         }
     }
-    match nums.parse() {
-        Ok(n) => Some(Node::float(n)),
-        Err(_) => None,
+
+    // Only commit the cursor advance once we know the buffer parses to a
+    // node; otherwise the caller would fall through past digits we've
+    // already consumed with nothing to show for it. An integer literal
+    // too large for `i64` (e.g. `99999999999999999999`) falls back to a
+    // `Float` rather than being silently dropped.
+    //
+    let node = if fractional || exponent {
+        buffer.parse::<f64>().ok().map(Node::float)
+    } else {
+        match buffer.parse::<i64>() {
+            Ok(i) => Some(Node::int(i)),
+            Err(_) => buffer.parse::<f64>().ok().map(Node::float),
+        }
+    };
+
+    if node.is_some() {
+        *chars = lookahead;
     }
+    node
 }
 
-fn parse_list(mut chars: &mut Peekable<str::Chars>) -> ParseResult {
+fn parse_list(mut chars: &mut Cursor, start: usize) -> ParseResult {
     if chars.peek() != Some(&'(') {
         return ParseResult::None;
     }
+    let open_pos = chars.position();
     chars.next();
     chomp(&mut chars);
     let elements = match parse_nodes(&mut chars) {
@@ -116,20 +308,34 @@ fn parse_list(mut chars: &mut Peekable<str::Chars>) -> ParseResult {
     };
     if chars.peek() == Some(&')') {
         chars.next();
-        ParseResult::Ok(Node::list_from_vec(elements))
+        let node = Node::list(list::List::from_vec(elements));
+        ParseResult::Ok(Spanned::new(node, (start, chars.offset)))
     } else {
-        ParseResult::Err(ParseError::BadList)
+        let (line, column) = open_pos;
+        ParseResult::Err(ParseError::BadList(line, column))
     }
 }
 
-/// Drop preceeding spaces
+/// Drop preceeding whitespace and line comments.
 ///
-fn chomp(chars: &mut Peekable<str::Chars>) {
-    while let Some(&c) = chars.peek() {
-        if c == ' ' {
-            chars.next();
-        } else {
-            break;
+/// A line comment starts with `;` and runs to, but not including, the
+/// next newline.
+///
+fn chomp(chars: &mut Cursor) {
+    loop {
+        match chars.peek() {
+            Some(&c) if c.is_whitespace() => {
+                chars.next();
+            }
+            Some(&';') => {
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            _ => break,
         }
     }
 }
@@ -139,14 +345,21 @@ fn chomp(chars: &mut Peekable<str::Chars>) {
 mod tests {
     use super::*;
     use super::ParseError::*;
-    use super::super::ast::Node;
+    use super::super::ast::{Node, Spanned};
+
+    /// Wrap a node for comparison against parser output. The dummy span
+    /// is ignored by `Spanned`'s `PartialEq` impl.
+    ///
+    fn sp(n: Node) -> Spanned<Node> {
+        Spanned::new(n, (0, 0))
+    }
 
     #[test]
     fn parse_test() {
         let input = "(+ 1 2)".to_string();
         let list =
-            Node::list_from_vec(vec![Node::atom("+".to_string()), Node::float(1), Node::float(2)]);
-        assert_eq!(parse(&input), Ok(vec![list]));
+            Node::list_from_vec(vec![Node::atom("+".to_string()), Node::int(1), Node::int(2)]);
+        assert_eq!(parse(&input), Ok(vec![sp(list)]));
     }
 
 
@@ -154,9 +367,9 @@ mod tests {
     fn parse_top_level_values() {
         let input = "() 1 /".to_string();
         assert_eq!(parse(&input),
-                   Ok(vec![Node::list_from_vec(vec![]),
-                           Node::float(1),
-                           Node::atom("/".to_string())]));
+                   Ok(vec![sp(Node::list_from_vec(vec![])),
+                           sp(Node::int(1)),
+                           sp(Node::atom("/".to_string()))]));
     }
 
     #[test]
@@ -167,95 +380,254 @@ mod tests {
     #[test]
     fn parse_list_of_num() {
         assert_eq!(parse(&"(123)".to_string()),
-                   Ok(vec![Node::list_from_vec(vec![Node::float(123)])]));
+                   Ok(vec![sp(Node::list_from_vec(vec![Node::int(123)]))]));
     }
 
     #[test]
     fn parse_incomplete_list() {
-        assert_eq!(parse(&"(123".to_string()), Err(BadList));
+        assert_eq!(parse(&"(123".to_string()), Err(BadList(1, 1)));
     }
 
 
     #[test]
     fn parse_multi_num_list() {
         assert_eq!(parse(&"(1 2 3)".to_string()),
-                   Ok(vec![Node::list_from_vec(vec![Node::float(1),
-                                                    Node::float(2),
-                                                    Node::float(3)])]));
+                   Ok(vec![sp(Node::list_from_vec(vec![Node::int(1),
+                                                        Node::int(2),
+                                                        Node::int(3)]))]));
     }
 
     #[test]
     fn parse_nested_list() {
         assert_eq!(parse(&"(1 (3))".to_string()),
-                   Ok(vec![Node::list_from_vec(vec![Node::float(1),
-                                                    Node::list_from_vec(vec![Node::float(3)])])]));
+                   Ok(vec![sp(Node::list_from_vec(vec![Node::int(1),
+                                                        Node::list_from_vec(vec![Node::int(3)])]))]));
     }
 
     #[test]
     fn parse_number_1_digit() {
-        assert_eq!(parse(&"5".to_string()), Ok(vec![Node::float(5)]));
+        assert_eq!(parse(&"5".to_string()), Ok(vec![sp(Node::int(5))]));
     }
 
     #[test]
     fn parse_number_2_digits() {
-        assert_eq!(parse(&"52".to_string()), Ok(vec![Node::float(52)]));
+        assert_eq!(parse(&"52".to_string()), Ok(vec![sp(Node::int(52))]));
+    }
+
+    #[test]
+    fn parse_number_negative_int() {
+        assert_eq!(parse(&"-52".to_string()), Ok(vec![sp(Node::int(-52))]));
+    }
+
+    #[test]
+    fn parse_number_float() {
+        assert_eq!(parse(&"5.5".to_string()), Ok(vec![sp(Node::float(5.5))]));
+    }
+
+    #[test]
+    fn parse_number_negative_float() {
+        assert_eq!(parse(&"-5.5".to_string()), Ok(vec![sp(Node::float(-5.5))]));
+    }
+
+    #[test]
+    fn parse_number_leading_dot_float() {
+        assert_eq!(parse(&".5".to_string()), Ok(vec![sp(Node::float(0.5))]));
+    }
+
+    #[test]
+    fn parse_number_trailing_dot_float() {
+        assert_eq!(parse(&"5.".to_string()), Ok(vec![sp(Node::float(5.0))]));
+    }
+
+    #[test]
+    fn parse_number_exponent() {
+        assert_eq!(parse(&"1e10".to_string()), Ok(vec![sp(Node::float(1e10))]));
+    }
+
+    #[test]
+    fn parse_number_exponent_with_sign() {
+        assert_eq!(parse(&"1e+10".to_string()), Ok(vec![sp(Node::float(1e10))]));
+        assert_eq!(parse(&"1e-10".to_string()), Ok(vec![sp(Node::float(1e-10))]));
+    }
+
+    #[test]
+    fn parse_number_uppercase_exponent() {
+        assert_eq!(parse(&"1E3".to_string()), Ok(vec![sp(Node::float(1e3))]));
+    }
+
+    #[test]
+    fn parse_number_overflowing_i64_falls_back_to_float() {
+        assert_eq!(parse(&"99999999999999999999".to_string()),
+                   Ok(vec![sp(Node::float(99999999999999999999.0))]));
+        assert_eq!(parse(&"(99999999999999999999 1)".to_string()),
+                   Ok(vec![sp(Node::list_from_vec(vec![Node::float(99999999999999999999.0),
+                                                        Node::int(1)]))]));
+    }
+
+    #[test]
+    fn parse_bare_minus_is_subtraction_atom() {
+        assert_eq!(parse(&"-".to_string()), Ok(vec![sp(Node::atom("-".to_string()))]));
+        assert_eq!(parse(&"(- 1 2)".to_string()),
+                   Ok(vec![sp(Node::list_from_vec(vec![Node::atom("-".to_string()),
+                                                        Node::int(1),
+                                                        Node::int(2)]))]));
     }
 
     #[test]
     fn parse_atom_lowercase() {
         assert_eq!(parse(&"hello".to_string()),
-                   Ok(vec![Node::atom("hello".to_string())]));
+                   Ok(vec![sp(Node::atom("hello".to_string()))]));
     }
 
     #[test]
     fn parse_atom_uppercase() {
         assert_eq!(parse(&"HELLO".to_string()),
-                   Ok(vec![Node::atom("HELLO".to_string())]));
+                   Ok(vec![sp(Node::atom("HELLO".to_string()))]));
     }
 
     #[test]
     fn parse_atom_mixed_case() {
         assert_eq!(parse(&"HelLO".to_string()),
-                   Ok(vec![Node::atom("HelLO".to_string())]));
+                   Ok(vec![sp(Node::atom("HelLO".to_string()))]));
     }
 
     #[test]
     fn parse_atom_with_dash() {
         assert_eq!(parse(&"hi-there".to_string()),
-                   Ok(vec![Node::atom("hi-there".to_string())]));
+                   Ok(vec![sp(Node::atom("hi-there".to_string()))]));
     }
 
     #[test]
     fn parse_atom_with_underscope() {
         assert_eq!(parse(&"hi_there".to_string()),
-                   Ok(vec![Node::atom("hi_there".to_string())]));
+                   Ok(vec![sp(Node::atom("hi_there".to_string()))]));
     }
 
     #[test]
     fn parse_atom_with_other_chars() {
         assert_eq!(parse(&"chars1234567890<~>!?\\/:;@#".to_string()),
-                   Ok(vec![Node::atom("chars1234567890<~>!?\\/:;@#".to_string())]));
+                   Ok(vec![sp(Node::atom("chars1234567890<~>!?\\/:;@#".to_string()))]));
     }
 
     #[test]
     fn parse_atom_true() {
-        assert_eq!(parse(&"true".to_string()), Ok(vec![Node::true_()]));
+        assert_eq!(parse(&"true".to_string()), Ok(vec![sp(Node::true_())]));
     }
 
     #[test]
     fn parse_atom_false() {
-        assert_eq!(parse(&"false".to_string()), Ok(vec![Node::false_()]));
+        assert_eq!(parse(&"false".to_string()), Ok(vec![sp(Node::false_())]));
     }
 
     #[test]
     fn parse_atom_blacklisted_starts() {
-        assert_eq!(parse(&mut "#".to_string()), Err(ReservedChar));
-        assert_eq!(parse(&mut "[".to_string()), Err(ReservedChar));
-        assert_eq!(parse(&mut "]".to_string()), Err(ReservedChar));
-        assert_eq!(parse(&mut "{".to_string()), Err(ReservedChar));
-        assert_eq!(parse(&mut "}".to_string()), Err(ReservedChar));
-        assert_eq!(parse(&mut "'".to_string()), Err(ReservedChar));
-        assert_eq!(parse(&mut "`".to_string()), Err(ReservedChar));
-        assert_eq!(parse(&mut "\"".to_string()), Err(ReservedChar));
+        assert_eq!(parse(&"#".to_string()), Err(ReservedChar(1, 1)));
+        assert_eq!(parse(&"[".to_string()), Err(ReservedChar(1, 1)));
+        assert_eq!(parse(&"]".to_string()), Err(ReservedChar(1, 1)));
+        assert_eq!(parse(&"{".to_string()), Err(ReservedChar(1, 1)));
+        assert_eq!(parse(&"}".to_string()), Err(ReservedChar(1, 1)));
+    }
+
+    #[test]
+    fn parse_reserved_char_reports_its_position() {
+        assert_eq!(parse(&"(1 2 #)".to_string()), Err(ReservedChar(1, 6)));
+        assert_eq!(parse(&"\n\n  #".to_string()), Err(ReservedChar(3, 3)));
+    }
+
+    #[test]
+    fn parse_string_plain() {
+        assert_eq!(parse(&"\"hello\"".to_string()),
+                   Ok(vec![sp(Node::string("hello".to_string()))]));
+    }
+
+    #[test]
+    fn parse_string_empty() {
+        assert_eq!(parse(&"\"\"".to_string()),
+                   Ok(vec![sp(Node::string("".to_string()))]));
+    }
+
+    #[test]
+    fn parse_string_escapes_round_trip() {
+        let input = "\"a\\nb\"".to_string();
+        let node = Node::string("a\nb".to_string());
+        assert_eq!(parse(&input), Ok(vec![sp(node)]));
+
+        let mut buffer = String::new();
+        parse(&input).unwrap()[0].node.print(&mut buffer).unwrap();
+        assert_eq!(buffer, input);
+    }
+
+    #[test]
+    fn parse_string_all_escapes() {
+        assert_eq!(parse(&"\"\\\"\\\\\\n\\t\\r\"".to_string()),
+                   Ok(vec![sp(Node::string("\"\\\n\t\r".to_string()))]));
+    }
+
+    #[test]
+    fn parse_string_bad_escape() {
+        assert_eq!(parse(&"\"\\q\"".to_string()), Err(BadEscape(1, 2)));
+    }
+
+    #[test]
+    fn parse_string_unterminated() {
+        assert_eq!(parse(&"\"abc".to_string()), Err(BadString(1, 1)));
+    }
+
+    #[test]
+    fn parse_multiline_with_comment() {
+        let input = "(+ 1 ; one\n 2)".to_string();
+        assert_eq!(parse(&input), parse(&"(+ 1 2)".to_string()));
+    }
+
+    #[test]
+    fn parse_leading_line_comment() {
+        let input = "; header\n(1 2)".to_string();
+        assert_eq!(parse(&input),
+                   Ok(vec![sp(Node::list_from_vec(vec![Node::int(1), Node::int(2)]))]));
+    }
+
+    #[test]
+    fn parse_with_tabs_and_carriage_returns() {
+        let input = "(1\t2\r\n3)".to_string();
+        assert_eq!(parse(&input),
+                   Ok(vec![sp(Node::list_from_vec(vec![Node::int(1),
+                                                        Node::int(2),
+                                                        Node::int(3)]))]));
+    }
+
+    #[test]
+    fn parse_span_covers_whole_node() {
+        let nodes = parse(&"(1 2)".to_string()).unwrap();
+        assert_eq!(nodes[0].span, (0, 5));
+    }
+
+    #[test]
+    fn parse_quote() {
+        assert_eq!(parse(&"'(1 2)".to_string()), parse(&"(quote (1 2))".to_string()));
+        assert_eq!(parse(&"'x".to_string()), parse(&"(quote x)".to_string()));
+    }
+
+    #[test]
+    fn parse_quasiquote() {
+        assert_eq!(parse(&"`(1 2)".to_string()),
+                   parse(&"(quasiquote (1 2))".to_string()));
+    }
+
+    #[test]
+    fn parse_unquote() {
+        assert_eq!(parse(&",x".to_string()), parse(&"(unquote x)".to_string()));
+    }
+
+    #[test]
+    fn parse_quote_inside_list() {
+        assert_eq!(parse(&"(a 'b)".to_string()),
+                   Ok(vec![sp(Node::list_from_vec(vec![Node::atom("a".to_string()),
+                                                        Node::list_from_vec(vec![Node::atom("quote".to_string()),
+                                                                                  Node::atom("b".to_string())])]))]));
+    }
+
+    #[test]
+    fn parse_quote_with_nothing_following_is_an_error() {
+        assert_eq!(parse(&"'".to_string()), Err(BadList(1, 2)));
     }
 }